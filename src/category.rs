@@ -0,0 +1,284 @@
+//! A `Category` trait generalizing `id`/`compose` to arbitrary objects and morphisms.
+//!
+//! The free [`id`](crate::id) and [`compose`](crate::compose) functions only
+//! describe a single category: the one whose objects are Rust types and whose
+//! morphisms are `Fn` values. A *category* in the algebraic sense is more
+//! general — it is a collection of **objects** together with **morphisms**
+//! (arrows) between them, equipped with:
+//!
+//! - an identity morphism `id_A : A → A` for every object `A`, and
+//! - a composition `g ∘ f : A → C` for every composable pair `f : A → B`,
+//!   `g : B → C`,
+//!
+//! satisfying the three laws
+//!
+//! - left identity:  `id_B ∘ f = f`,
+//! - right identity: `f ∘ id_A = f`,
+//! - associativity:  `h ∘ (g ∘ f) = (h ∘ g) ∘ f`.
+//!
+//! Here a category is modelled at run time: [`Category::Object`] and
+//! [`Category::Morphism`] are plain data and [`Category::dom`]/[`Category::cod`]
+//! recover the endpoints of an arrow. This lets us describe categories that the
+//! compile-time [`compose`](crate::compose) cannot, such as the free category
+//! over a finite graph, while still exercising the same laws.
+
+/// A category: objects, morphisms between them, identities and composition.
+///
+/// Implementations describe the objects and arrows as data and are responsible
+/// for their endpoints ([`dom`](Category::dom)/[`cod`](Category::cod)),
+/// the identity arrow at each object, and the composite of a composable pair.
+///
+/// [`compose`](Category::compose) follows the usual mathematical order: the
+/// *second* argument `f` is applied first, so `compose(g, f)` is `g ∘ f`. It is
+/// a precondition that the pair is composable, i.e. `cod(f) == dom(g)`.
+pub trait Category {
+    /// The objects of the category.
+    type Object: Clone + PartialEq;
+
+    /// The morphisms (arrows) of the category.
+    type Morphism: Clone + PartialEq;
+
+    /// The domain (source object) of a morphism.
+    fn dom(&self, f: &Self::Morphism) -> Self::Object;
+
+    /// The codomain (target object) of a morphism.
+    fn cod(&self, f: &Self::Morphism) -> Self::Object;
+
+    /// The identity morphism `id_a : a → a`.
+    fn identity(&self, a: Self::Object) -> Self::Morphism;
+
+    /// The composite `g ∘ f`, defined when `cod(f) == dom(g)`.
+    fn compose(&self, g: &Self::Morphism, f: &Self::Morphism) -> Self::Morphism;
+}
+
+/// Checks the left and right identity laws for a single morphism `f`:
+/// `id_cod(f) ∘ f = f` and `f ∘ id_dom(f) = f`.
+pub fn check_identity_laws<C: Category>(cat: &C, f: &C::Morphism) -> bool {
+    let right = cat.compose(f, &cat.identity(cat.dom(f)));
+    let left = cat.compose(&cat.identity(cat.cod(f)), f);
+    &right == f && &left == f
+}
+
+/// Checks the associativity law `h ∘ (g ∘ f) = (h ∘ g) ∘ f` for a composable
+/// triple `f : A → B`, `g : B → C`, `h : C → D`.
+pub fn check_associativity<C: Category>(
+    cat: &C,
+    h: &C::Morphism,
+    g: &C::Morphism,
+    f: &C::Morphism,
+) -> bool {
+    let left = cat.compose(h, &cat.compose(g, f));
+    let right = cat.compose(&cat.compose(h, g), f);
+    left == right
+}
+
+/// The category **FinSet** of finite sets and the functions between them.
+///
+/// A finite set is identified with its cardinality `n`, whose elements are the
+/// numbers `0..n`. A function `f : n → m` is stored as its table of images, so
+/// `table[x]` is `f(x)`. This is the concrete, data-level incarnation of the
+/// function category that the free [`compose`](crate::compose) describes at the
+/// type level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FinSet;
+
+/// A morphism of [`FinSet`]: a function `dom → cod` given by its image table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinFunction {
+    dom: usize,
+    cod: usize,
+    table: Vec<usize>,
+}
+
+impl FinFunction {
+    /// Builds a function `dom → cod` from its table of images, panicking if the
+    /// table has the wrong length or points outside the codomain.
+    pub fn new(dom: usize, cod: usize, table: Vec<usize>) -> Self {
+        assert_eq!(table.len(), dom, "table must have one image per element");
+        assert!(
+            table.iter().all(|&y| y < cod),
+            "every image must lie in the codomain"
+        );
+        FinFunction { dom, cod, table }
+    }
+
+    /// The image `f(x)` of an element of the domain.
+    pub fn apply(&self, x: usize) -> usize {
+        self.table[x]
+    }
+}
+
+impl Category for FinSet {
+    type Object = usize;
+    type Morphism = FinFunction;
+
+    fn dom(&self, f: &Self::Morphism) -> Self::Object {
+        f.dom
+    }
+
+    fn cod(&self, f: &Self::Morphism) -> Self::Object {
+        f.cod
+    }
+
+    fn identity(&self, a: Self::Object) -> Self::Morphism {
+        FinFunction::new(a, a, (0..a).collect())
+    }
+
+    fn compose(&self, g: &Self::Morphism, f: &Self::Morphism) -> Self::Morphism {
+        assert_eq!(f.cod, g.dom, "morphisms are not composable");
+        let table = f.table.iter().map(|&x| g.table[x]).collect();
+        FinFunction::new(f.dom, g.cod, table)
+    }
+}
+
+/// The free category generated by a finite directed graph.
+///
+/// Objects are the graph's nodes `0..objects`; morphisms are the (possibly
+/// empty) *paths* that can be walked along the generating edges. Composition is
+/// path concatenation and the identity at a node is the empty path there, so
+/// the category laws hold by construction: concatenation is associative and the
+/// empty path is its unit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FreeCategory {
+    objects: usize,
+    generators: Vec<(usize, usize)>,
+}
+
+/// A morphism of a [`FreeCategory`]: a path recorded as the generators it
+/// traverses, together with the endpoints the path runs between.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path {
+    src: usize,
+    dst: usize,
+    edges: Vec<usize>,
+}
+
+impl FreeCategory {
+    /// Builds the free category over `objects` nodes and the given generating
+    /// edges `(source, target)`, panicking if an edge references a missing node.
+    pub fn new(objects: usize, generators: Vec<(usize, usize)>) -> Self {
+        assert!(
+            generators.iter().all(|&(s, t)| s < objects && t < objects),
+            "every generator must connect existing nodes"
+        );
+        FreeCategory {
+            objects,
+            generators,
+        }
+    }
+
+    /// The number of objects (nodes) of the category.
+    pub fn objects(&self) -> usize {
+        self.objects
+    }
+
+    /// The number of generating edges.
+    pub fn generator_count(&self) -> usize {
+        self.generators.len()
+    }
+
+    /// The single-edge path for the `i`-th generating arrow.
+    pub fn generator(&self, i: usize) -> Path {
+        let (src, dst) = self.generators[i];
+        Path {
+            src,
+            dst,
+            edges: vec![i],
+        }
+    }
+}
+
+impl Category for FreeCategory {
+    type Object = usize;
+    type Morphism = Path;
+
+    fn dom(&self, f: &Self::Morphism) -> Self::Object {
+        f.src
+    }
+
+    fn cod(&self, f: &Self::Morphism) -> Self::Object {
+        f.dst
+    }
+
+    fn identity(&self, a: Self::Object) -> Self::Morphism {
+        Path {
+            src: a,
+            dst: a,
+            edges: Vec::new(),
+        }
+    }
+
+    fn compose(&self, g: &Self::Morphism, f: &Self::Morphism) -> Self::Morphism {
+        assert_eq!(f.dst, g.src, "paths do not meet end to end");
+        let mut edges = f.edges.clone();
+        edges.extend_from_slice(&g.edges);
+        Path {
+            src: f.src,
+            dst: g.dst,
+            edges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finset_left_identity_law() {
+        let cat = FinSet;
+        let f = FinFunction::new(3, 2, vec![1, 0, 1]);
+        // id_cod ∘ f = f
+        let composed = cat.compose(&cat.identity(cat.cod(&f)), &f);
+        assert_eq!(composed, f);
+    }
+
+    #[test]
+    fn finset_right_identity_law() {
+        let cat = FinSet;
+        let f = FinFunction::new(3, 2, vec![1, 0, 1]);
+        // f ∘ id_dom = f
+        let composed = cat.compose(&f, &cat.identity(cat.dom(&f)));
+        assert_eq!(composed, f);
+    }
+
+    #[test]
+    fn finset_associativity_law() {
+        let cat = FinSet;
+        let f = FinFunction::new(3, 2, vec![1, 0, 1]);
+        let g = FinFunction::new(2, 4, vec![3, 1]);
+        let h = FinFunction::new(4, 2, vec![0, 1, 1, 0]);
+        assert!(check_associativity(&cat, &h, &g, &f));
+        assert!(check_identity_laws(&cat, &f));
+    }
+
+    #[test]
+    fn free_category_left_identity_law() {
+        // 0 --a--> 1 --b--> 2
+        let cat = FreeCategory::new(3, vec![(0, 1), (1, 2)]);
+        let a = cat.generator(0);
+        let composed = cat.compose(&cat.identity(cat.cod(&a)), &a);
+        assert_eq!(composed, a);
+    }
+
+    #[test]
+    fn free_category_right_identity_law() {
+        let cat = FreeCategory::new(3, vec![(0, 1), (1, 2)]);
+        let a = cat.generator(0);
+        let composed = cat.compose(&a, &cat.identity(cat.dom(&a)));
+        assert_eq!(composed, a);
+    }
+
+    #[test]
+    fn free_category_associativity_law() {
+        // 0 --a--> 1 --b--> 2 --c--> 3
+        let cat = FreeCategory::new(4, vec![(0, 1), (1, 2), (2, 3)]);
+        let a = cat.generator(0);
+        let b = cat.generator(1);
+        let c = cat.generator(2);
+        assert!(check_associativity(&cat, &c, &b, &a));
+        assert!(check_identity_laws(&cat, &b));
+        // b ∘ a is the path [a, b] from 0 to 2.
+        assert_eq!(cat.compose(&b, &a).edges, vec![0, 1]);
+    }
+}
@@ -0,0 +1,194 @@
+//! A `Functor` trait mapping between categories while preserving `id`/`compose`.
+//!
+//! A *functor* `F` between two categories sends each object `A` to an object
+//! `F(A)` and each morphism `f : A → B` to a morphism `F(f) : F(A) → F(B)` in a
+//! way that respects the categorical structure:
+//!
+//! - `F(id_A) = id_{F(A)}`                 (identity law)
+//! - `F(g ∘ f) = F(g) ∘ F(f)`              (composition law)
+//!
+//! These are exactly the obligations a law-abiding functor must discharge.
+//! Here we work with *endofunctors on the function category*: the object
+//! mapping is the type-level [`Functor::Map`] and the morphism mapping is
+//! [`Functor::map_mor`], which lifts a function `A → B` to a function
+//! `F<A> → F<B>`. The familiar `.map` methods of [`Option`], [`Vec`] and
+//! [`Result`] are precisely such liftings.
+
+/// An endofunctor on the function category.
+///
+/// The object part is the associated constructor [`Map`](Functor::Map), taking
+/// a type `T` to `F<T>`. The morphism part is [`map_mor`](Functor::map_mor),
+/// lifting a function `A → B` to `F<A> → F<B>`.
+pub trait Functor {
+    /// The action of the functor on objects: `T ↦ F<T>`.
+    type Map<T>;
+
+    /// The action of the functor on morphisms: lift `f : A → B` to
+    /// `F(f) : F<A> → F<B>`.
+    fn map_mor<A, B, Fun>(f: Fun, fa: Self::Map<A>) -> Self::Map<B>
+    where
+        Fun: Fn(A) -> B;
+}
+
+/// The identity functor, leaving both objects and morphisms untouched.
+///
+/// Its morphism mapping is the crate's [`id`](crate::id) lifted to functions:
+/// `map_mor(f, x) = f(x)`, so `map_mor(id, x) = id(x) = x`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Identity;
+
+impl Functor for Identity {
+    type Map<T> = T;
+
+    fn map_mor<A, B, Fun>(f: Fun, fa: Self::Map<A>) -> Self::Map<B>
+    where
+        Fun: Fn(A) -> B,
+    {
+        f(fa)
+    }
+}
+
+/// The composite functor `G ∘ F`, applying `F` and then `G`.
+///
+/// On objects it is `T ↦ G<F<T>>`; on morphisms it lifts `f` first through `F`
+/// and then through `G`, mirroring how [`compose`](crate::compose) chains the
+/// underlying functions.
+pub struct Compose<G, F>(core::marker::PhantomData<(G, F)>);
+
+impl<G, F> Functor for Compose<G, F>
+where
+    G: Functor,
+    F: Functor,
+{
+    type Map<T> = G::Map<F::Map<T>>;
+
+    fn map_mor<A, B, Fun>(f: Fun, fa: Self::Map<A>) -> Self::Map<B>
+    where
+        Fun: Fn(A) -> B,
+    {
+        G::map_mor(move |inner: F::Map<A>| F::map_mor(&f, inner), fa)
+    }
+}
+
+/// The `Option` endofunctor, lifting `f` over a possibly-absent value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OptionFunctor;
+
+impl Functor for OptionFunctor {
+    type Map<T> = Option<T>;
+
+    fn map_mor<A, B, Fun>(f: Fun, fa: Self::Map<A>) -> Self::Map<B>
+    where
+        Fun: Fn(A) -> B,
+    {
+        fa.map(f)
+    }
+}
+
+/// The `Vec` endofunctor, lifting `f` elementwise over a sequence.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VecFunctor;
+
+impl Functor for VecFunctor {
+    type Map<T> = Vec<T>;
+
+    fn map_mor<A, B, Fun>(f: Fun, fa: Self::Map<A>) -> Self::Map<B>
+    where
+        Fun: Fn(A) -> B,
+    {
+        fa.into_iter().map(f).collect()
+    }
+}
+
+/// The `Result<_, E>` endofunctor, lifting `f` over the success value while
+/// leaving the error `E` fixed.
+pub struct ResultFunctor<E>(core::marker::PhantomData<E>);
+
+impl<E> Functor for ResultFunctor<E> {
+    type Map<T> = Result<T, E>;
+
+    fn map_mor<A, B, Fun>(f: Fun, fa: Self::Map<A>) -> Self::Map<B>
+    where
+        Fun: Fn(A) -> B,
+    {
+        fa.map(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compose, id};
+
+    #[test]
+    fn option_identity_law() {
+        // F(id) = id_{F}
+        for x in -10..10 {
+            assert_eq!(OptionFunctor::map_mor(id, Some(x)), Some(x));
+        }
+        assert_eq!(OptionFunctor::map_mor(id::<i32>, None), None);
+    }
+
+    #[test]
+    fn option_composition_law() {
+        let f = |x: i32| x + 1;
+        let g = |x: i32| x * 2;
+        // F(g ∘ f) = F(g) ∘ F(f)
+        for x in -10..10 {
+            let fused = OptionFunctor::map_mor(compose(f, g), Some(x));
+            let stepwise = OptionFunctor::map_mor(g, OptionFunctor::map_mor(f, Some(x)));
+            assert_eq!(fused, stepwise);
+        }
+    }
+
+    #[test]
+    fn vec_identity_law() {
+        let xs: Vec<i32> = (-10..10).collect();
+        assert_eq!(VecFunctor::map_mor(id, xs.clone()), xs);
+    }
+
+    #[test]
+    fn vec_composition_law() {
+        let f = |x: i32| x + 1;
+        let g = |x: i32| x * 2;
+        let xs: Vec<i32> = (-10..10).collect();
+        let fused = VecFunctor::map_mor(compose(f, g), xs.clone());
+        let stepwise = VecFunctor::map_mor(g, VecFunctor::map_mor(f, xs));
+        assert_eq!(fused, stepwise);
+    }
+
+    #[test]
+    fn result_laws() {
+        let f = |x: i32| x + 1;
+        let g = |x: i32| x * 2;
+        for x in -10..10 {
+            let ok: Result<i32, String> = Ok(x);
+            assert_eq!(ResultFunctor::<String>::map_mor(id, ok.clone()), ok);
+            let fused = ResultFunctor::<String>::map_mor(compose(f, g), ok.clone());
+            let stepwise =
+                ResultFunctor::<String>::map_mor(g, ResultFunctor::<String>::map_mor(f, ok));
+            assert_eq!(fused, stepwise);
+        }
+        let err: Result<i32, String> = Err("boom".to_string());
+        assert_eq!(ResultFunctor::<String>::map_mor(f, err.clone()), err);
+    }
+
+    #[test]
+    fn identity_functor_is_the_identity() {
+        let f = |x: i32| x * 3;
+        for x in -10..10 {
+            assert_eq!(Identity::map_mor(f, x), f(x));
+            assert_eq!(Identity::map_mor(id, x), x);
+        }
+    }
+
+    #[test]
+    fn compose_functor_matches_nested_map() {
+        // Compose<Vec, Option> acts on Vec<Option<T>>.
+        let f = |x: i32| x + 100;
+        let data = vec![Some(1), None, Some(3)];
+        let lifted = Compose::<VecFunctor, OptionFunctor>::map_mor(f, data.clone());
+        let manual: Vec<Option<i32>> = data.into_iter().map(|o| o.map(f)).collect();
+        assert_eq!(lifted, manual);
+    }
+}
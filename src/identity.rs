@@ -20,8 +20,8 @@ mod tests {
         assert_eq!(id(42), 42);
         assert_eq!(id("hello"), "hello");
         assert_eq!(id(vec![1, 2, 3]), vec![1, 2, 3]);
-        assert_eq!(id(true), true);
-        assert_eq!(id(3.14), 3.14);
+        assert!(id(true));
+        assert_eq!(id(2.5), 2.5);
     }
     
     #[test]
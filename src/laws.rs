@@ -0,0 +1,236 @@
+//! Property-based checking of the category laws.
+//!
+//! The per-module tests exercise the identity and associativity laws over a
+//! hardcoded `-10..10` loop. This module adds, alongside those ad hoc loops,
+//! reusable, randomized harnesses exposed for any future structure to call.
+//!
+//! There are two layers, because the crate has two kinds of morphism:
+//!
+//! - **Any [`Category`] instance** — where morphisms are *data* — is covered by
+//!   [`prop_identity_laws`] and [`prop_associativity`]. A structure opts in by
+//!   implementing [`SampleMorphisms`], a composability-aware generator of random
+//!   morphisms; the harness then feeds those through [`Category::compose`] and
+//!   the [`category`](crate::category) law checkers. [`FinSet`] and
+//!   [`FreeCategory`] implement it here, so they get randomized coverage for
+//!   free.
+//! - **The function category** — where morphisms are Rust `Fn` values that are
+//!   neither `PartialEq` nor `Category::Morphism` — is covered by
+//!   [`quickcheck_identity_laws`] and [`quickcheck_associativity`], which sample
+//!   inputs from [`Arbitrary`] and *shrink* a failing input to a minimal case.
+//!   (The closures capture the morphisms under test, so the property is driven
+//!   by hand rather than through `quickcheck`'s `Testable`, which is implemented
+//!   only for `fn` pointers.)
+
+use std::fmt::Debug;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::category::{self, Category, FinFunction, FinSet, FreeCategory, Path};
+use crate::{compose, id};
+
+/// How many random samples each law is checked against.
+const ITERATIONS: usize = 100;
+
+/// Upper bound on the cardinalities / path lengths the samplers generate.
+const MAX_SIZE: usize = 5;
+
+/// A [`Category`] whose morphisms can be sampled at random for property testing.
+///
+/// Associativity needs a *composable* triple, so beyond a bare
+/// [`arbitrary_morphism`](SampleMorphisms::arbitrary_morphism) the sampler must
+/// be able to extend a morphism with a randomly chosen composable successor via
+/// [`arbitrary_after`](SampleMorphisms::arbitrary_after).
+pub trait SampleMorphisms: Category {
+    /// Samples an arbitrary morphism of the category.
+    fn arbitrary_morphism(&self, g: &mut Gen) -> Self::Morphism;
+
+    /// Samples an arbitrary morphism whose domain is `cod(f)`, so that it can be
+    /// composed after `f`.
+    fn arbitrary_after(&self, g: &mut Gen, f: &Self::Morphism) -> Self::Morphism;
+}
+
+/// Checks the identity laws on random morphisms of a [`Category`]:
+/// `id_cod(f) ∘ f = f` and `f ∘ id_dom(f) = f`.
+pub fn prop_identity_laws<C: SampleMorphisms>(cat: &C) {
+    let mut g = Gen::new(MAX_SIZE);
+    for _ in 0..ITERATIONS {
+        let f = cat.arbitrary_morphism(&mut g);
+        assert!(
+            category::check_identity_laws(cat, &f),
+            "identity law falsified"
+        );
+    }
+}
+
+/// Checks associativity on random composable triples of a [`Category`]:
+/// `h ∘ (g ∘ f) = (h ∘ g) ∘ f`.
+pub fn prop_associativity<C: SampleMorphisms>(cat: &C) {
+    let mut g = Gen::new(MAX_SIZE);
+    for _ in 0..ITERATIONS {
+        let f = cat.arbitrary_morphism(&mut g);
+        let mid = cat.arbitrary_after(&mut g, &f);
+        let h = cat.arbitrary_after(&mut g, &mid);
+        assert!(
+            category::check_associativity(cat, &h, &mid, &f),
+            "associativity law falsified"
+        );
+    }
+}
+
+/// Checks the left and right identity laws for a function `f : A → B` over
+/// random inputs: `id_B ∘ f = f` and `f ∘ id_A = f`.
+pub fn quickcheck_identity_laws<A, B, F>(f: F)
+where
+    A: Arbitrary + Debug,
+    B: PartialEq,
+    F: Fn(A) -> B,
+{
+    for_all(|x: &A| {
+        let expected = f(x.clone());
+        // f then id_B, and id_A then f.
+        compose(&f, id::<B>)(x.clone()) == expected && compose(id::<A>, &f)(x.clone()) == expected
+    });
+}
+
+/// Checks associativity for a composable triple of functions `f : A → B`,
+/// `g : B → C`, `h : C → D` over random inputs: `h ∘ (g ∘ f) = (h ∘ g) ∘ f`.
+pub fn quickcheck_associativity<A, B, C, D, F, G, H>(f: F, g: G, h: H)
+where
+    A: Arbitrary + Debug,
+    D: PartialEq,
+    F: Fn(A) -> B,
+    G: Fn(B) -> C,
+    H: Fn(C) -> D,
+{
+    for_all(|x: &A| {
+        let left = compose(compose(&f, &g), &h)(x.clone());
+        let right = compose(&f, compose(&g, &h))(x.clone());
+        left == right
+    });
+}
+
+/// Samples random inputs of type `A` and asserts `pred` holds for each,
+/// shrinking and panicking on the first counterexample.
+fn for_all<A, P>(mut pred: P)
+where
+    A: Arbitrary + Debug,
+    P: FnMut(&A) -> bool,
+{
+    let mut gen = Gen::new(100);
+    for _ in 0..ITERATIONS {
+        let input = A::arbitrary(&mut gen);
+        if !pred(&input) {
+            let minimal = shrink_counterexample(input, &mut pred);
+            panic!("law falsified on input: {minimal:?}");
+        }
+    }
+}
+
+/// Repeatedly replaces a failing input with a smaller failing shrink until no
+/// shrink falsifies `pred`, yielding a minimal counterexample.
+fn shrink_counterexample<A, P>(failing: A, pred: &mut P) -> A
+where
+    A: Arbitrary,
+    P: FnMut(&A) -> bool,
+{
+    let mut current = failing;
+    'outer: loop {
+        for candidate in current.shrink() {
+            if !pred(&candidate) {
+                current = candidate;
+                continue 'outer;
+            }
+        }
+        return current;
+    }
+}
+
+/// Samples a number in `0..n`. Requires `n > 0`.
+fn bounded(g: &mut Gen, n: usize) -> usize {
+    usize::arbitrary(g) % n
+}
+
+impl SampleMorphisms for FinSet {
+    fn arbitrary_morphism(&self, g: &mut Gen) -> FinFunction {
+        let dom = bounded(g, MAX_SIZE + 1);
+        let cod = bounded(g, MAX_SIZE) + 1;
+        let table = (0..dom).map(|_| bounded(g, cod)).collect();
+        FinFunction::new(dom, cod, table)
+    }
+
+    fn arbitrary_after(&self, g: &mut Gen, f: &FinFunction) -> FinFunction {
+        let dom = self.cod(f);
+        let cod = bounded(g, MAX_SIZE) + 1;
+        let table = (0..dom).map(|_| bounded(g, cod)).collect();
+        FinFunction::new(dom, cod, table)
+    }
+}
+
+impl SampleMorphisms for FreeCategory {
+    fn arbitrary_morphism(&self, g: &mut Gen) -> Path {
+        let start = bounded(g, self.objects());
+        self.random_walk(g, start)
+    }
+
+    fn arbitrary_after(&self, g: &mut Gen, f: &Path) -> Path {
+        self.random_walk(g, self.cod(f))
+    }
+}
+
+impl FreeCategory {
+    /// Builds a random path of up to [`MAX_SIZE`] generating edges starting at
+    /// `start`, walking only along edges that leave the current node.
+    fn random_walk(&self, g: &mut Gen, start: usize) -> Path {
+        let mut path = self.identity(start);
+        for _ in 0..bounded(g, MAX_SIZE + 1) {
+            let here = self.cod(&path);
+            let outgoing: Vec<Path> = (0..self.generator_count())
+                .map(|i| self.generator(i))
+                .filter(|e| self.dom(e) == here)
+                .collect();
+            if outgoing.is_empty() {
+                break;
+            }
+            let edge = &outgoing[bounded(g, outgoing.len())];
+            path = self.compose(edge, &path);
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finset_laws_hold_randomly() {
+        let cat = FinSet;
+        prop_identity_laws(&cat);
+        prop_associativity(&cat);
+    }
+
+    #[test]
+    fn free_category_laws_hold_randomly() {
+        // 0 --a--> 1 --b--> 2 --c--> 0, a little graph with a cycle.
+        let cat = FreeCategory::new(3, vec![(0, 1), (1, 2), (2, 0)]);
+        prop_identity_laws(&cat);
+        prop_associativity(&cat);
+    }
+
+    #[test]
+    fn function_identity_laws() {
+        // Wrapping arithmetic keeps the morphisms total over all of `i32`, which
+        // `Arbitrary` samples freely (unlike the old `-10..10` loops).
+        quickcheck_identity_laws(|x: i32| x.wrapping_mul(2));
+        quickcheck_identity_laws(|x: i32| x.to_string());
+    }
+
+    #[test]
+    fn function_associativity_law() {
+        quickcheck_associativity(
+            |x: i32| x.wrapping_add(1),
+            |x: i32| x.wrapping_mul(2),
+            |x: i32| x.wrapping_sub(3),
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! A small category-theory playground in Rust.
+//!
+//! The crate started life as a demonstration of the two operations that every
+//! category is built from — the identity morphism and composition — specialised
+//! to Rust functions (the category **Set**/**Hask**). The [`category`] module
+//! generalises that structure to arbitrary objects and morphisms so that other
+//! categories (finite functions, free categories over a graph, …) can reuse the
+//! same identity/composition law machinery.
+
+pub mod category;
+pub mod composition;
+pub mod functor;
+pub mod identity;
+pub mod laws;
+pub mod monoidal;
+pub mod natural_transformation;
+pub mod object_free;
+pub mod pipe;
+
+pub use composition::compose;
+pub use identity::id;
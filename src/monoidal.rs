@@ -0,0 +1,201 @@
+//! Monoidal structure on a [`Category`], with pentagon/triangle coherence
+//! checking.
+//!
+//! A *monoidal category* equips a category with a tensor product `⊗` that
+//! combines two objects into one and two morphisms into one, a unit object `I`,
+//! and three families of structural isomorphisms:
+//!
+//! - the associator `α_{A,B,C} : (A⊗B)⊗C ≅ A⊗(B⊗C)`,
+//! - the left unitor  `λ_A : I⊗A ≅ A`, and
+//! - the right unitor `ρ_A : A⊗I ≅ A`.
+//!
+//! Mac Lane's coherence theorem says every diagram built from these
+//! isomorphisms commutes, and that this follows from just two of them:
+//!
+//! - the **pentagon**, relating the five ways to reassociate `((A⊗B)⊗C)⊗D`, and
+//! - the **triangle**, relating `α` with the unitors on `(A⊗I)⊗B`.
+//!
+//! Both are expressed here as equalities of composites built from the ambient
+//! [`Category::compose`], so the same machinery that checks the category laws
+//! checks coherence. The [`FinSet`] instance is the Cartesian monoidal
+//! structure — `⊗` is the product of finite sets (the set of element *tuples*)
+//! and `I` is the one-element set `()` — realised on the data-level function
+//! category. With elements encoded row-major the structural isomorphisms are
+//! identities (the encoding is strictly associative and unital), so the
+//! coherence diagrams commute on the nose.
+
+use crate::category::{Category, FinFunction, FinSet};
+
+/// A [`Category`] carrying a monoidal product.
+///
+/// [`tensor_mor`](MonoidalCategory::tensor_mor) must be functorial and the
+/// structural isomorphisms natural; [`check_pentagon`] and [`check_triangle`]
+/// verify the two coherence conditions those data must satisfy.
+pub trait MonoidalCategory: Category {
+    /// The unit object `I` of the tensor product.
+    fn unit(&self) -> Self::Object;
+
+    /// The tensor product `A ⊗ B` of two objects.
+    fn tensor_ob(&self, a: &Self::Object, b: &Self::Object) -> Self::Object;
+
+    /// The tensor product `f ⊗ g` of two morphisms.
+    fn tensor_mor(&self, f: &Self::Morphism, g: &Self::Morphism) -> Self::Morphism;
+
+    /// The associator `α_{A,B,C} : (A⊗B)⊗C → A⊗(B⊗C)`.
+    fn associator(
+        &self,
+        a: &Self::Object,
+        b: &Self::Object,
+        c: &Self::Object,
+    ) -> Self::Morphism;
+
+    /// The left unitor `λ_A : I⊗A → A`.
+    fn left_unitor(&self, a: &Self::Object) -> Self::Morphism;
+
+    /// The right unitor `ρ_A : A⊗I → A`.
+    fn right_unitor(&self, a: &Self::Object) -> Self::Morphism;
+}
+
+/// Checks the pentagon coherence equation at the objects `A, B, C, D`:
+/// `α_{A,B,C⊗D} ∘ α_{A⊗B,C,D} = (id_A ⊗ α_{B,C,D}) ∘ α_{A,B⊗C,D} ∘ (α_{A,B,C} ⊗ id_D)`.
+pub fn check_pentagon<M: MonoidalCategory>(
+    cat: &M,
+    a: &M::Object,
+    b: &M::Object,
+    c: &M::Object,
+    d: &M::Object,
+) -> bool {
+    let id_a = cat.identity(a.clone());
+    let id_d = cat.identity(d.clone());
+    let ab = cat.tensor_ob(a, b);
+    let cd = cat.tensor_ob(c, d);
+    let bc = cat.tensor_ob(b, c);
+
+    // The two-associator route along the top of the pentagon.
+    let top = cat.compose(&cat.associator(a, b, &cd), &cat.associator(&ab, c, d));
+
+    // The three-associator route along the bottom.
+    let bottom = cat.compose(
+        &cat.tensor_mor(&id_a, &cat.associator(b, c, d)),
+        &cat.compose(
+            &cat.associator(a, &bc, d),
+            &cat.tensor_mor(&cat.associator(a, b, c), &id_d),
+        ),
+    );
+
+    top == bottom
+}
+
+/// Checks the triangle coherence equation at the objects `A, B`:
+/// `(id_A ⊗ λ_B) ∘ α_{A,I,B} = ρ_A ⊗ id_B`.
+pub fn check_triangle<M: MonoidalCategory>(cat: &M, a: &M::Object, b: &M::Object) -> bool {
+    let i = cat.unit();
+    let left = cat.compose(
+        &cat.tensor_mor(&cat.identity(a.clone()), &cat.left_unitor(b)),
+        &cat.associator(a, &i, b),
+    );
+    let right = cat.tensor_mor(&cat.right_unitor(a), &cat.identity(b.clone()));
+    left == right
+}
+
+impl MonoidalCategory for FinSet {
+    fn unit(&self) -> Self::Object {
+        1
+    }
+
+    fn tensor_ob(&self, a: &Self::Object, b: &Self::Object) -> Self::Object {
+        a * b
+    }
+
+    fn tensor_mor(&self, f: &Self::Morphism, g: &Self::Morphism) -> Self::Morphism {
+        // Elements of the product are paired row-major: `(i, j) ↦ i * gc + j`,
+        // where `gc` is the (co)domain width of `g`. `(f ⊗ g)(i, j) = (f i, g j)`.
+        let g_dom = self.dom(g);
+        let g_cod = self.cod(g);
+        let table = (0..self.dom(f) * g_dom)
+            .map(|e| {
+                let (i, j) = (e / g_dom, e % g_dom);
+                f.apply(i) * g_cod + g.apply(j)
+            })
+            .collect();
+        FinFunction::new(self.dom(f) * g_dom, self.cod(f) * g_cod, table)
+    }
+
+    fn associator(
+        &self,
+        a: &Self::Object,
+        b: &Self::Object,
+        c: &Self::Object,
+    ) -> Self::Morphism {
+        // Row-major pairing makes `((i,j),k)` and `(i,(j,k))` the same index, so
+        // the associator is the identity on `a*b*c`.
+        self.identity(a * b * c)
+    }
+
+    fn left_unitor(&self, a: &Self::Object) -> Self::Morphism {
+        // `I ⊗ A` has the same cardinality as `A`, indexed identically.
+        self.identity(self.tensor_ob(&self.unit(), a))
+    }
+
+    fn right_unitor(&self, a: &Self::Object) -> Self::Morphism {
+        self.identity(self.tensor_ob(a, &self.unit()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tensor_of_functions_pairs_images() {
+        let cat = FinSet;
+        let f = FinFunction::new(2, 3, vec![2, 0]); // 0↦2, 1↦0
+        let g = FinFunction::new(2, 2, vec![1, 1]); // 0↦1, 1↦1
+        let fg = cat.tensor_mor(&f, &g);
+        // (i, j) ↦ (f i, g j), paired row-major into 3*2 = 6.
+        assert_eq!(cat.dom(&fg), 4);
+        assert_eq!(cat.cod(&fg), 6);
+        // element (1, 0) = index 2 ↦ (0, 1) = index 0*2 + 1 = 1.
+        assert_eq!(fg.apply(2), 1);
+    }
+
+    #[test]
+    fn pentagon_holds() {
+        let cat = FinSet;
+        assert!(check_pentagon(&cat, &2, &3, &2, &2));
+    }
+
+    #[test]
+    fn triangle_holds() {
+        let cat = FinSet;
+        assert!(check_triangle(&cat, &3, &4));
+    }
+
+    #[test]
+    fn associator_is_natural() {
+        // The Cartesian structure is strict, so `pentagon_holds`/`triangle_holds`
+        // compare `identity` with `identity`. Naturality of the associator, by
+        // contrast, threads *non-identity* morphisms through both reassociation
+        // routes, so it genuinely exercises `tensor_mor`/`compose`: a wrong
+        // pairing in `tensor_mor` would make the two routes disagree.
+        let cat = FinSet;
+        let f = FinFunction::new(2, 3, vec![1, 2]);
+        let g = FinFunction::new(2, 2, vec![1, 0]);
+        let h = FinFunction::new(3, 2, vec![0, 1, 1]);
+
+        let dom_assoc = cat.associator(&cat.dom(&f), &cat.dom(&g), &cat.dom(&h));
+        let cod_assoc = cat.associator(&cat.cod(&f), &cat.cod(&g), &cat.cod(&h));
+
+        // α_cod ∘ ((f⊗g)⊗h) = (f⊗(g⊗h)) ∘ α_dom
+        let route_left = cat.compose(&cod_assoc, &cat.tensor_mor(&cat.tensor_mor(&f, &g), &h));
+        let route_right = cat.compose(&cat.tensor_mor(&f, &cat.tensor_mor(&g, &h)), &dom_assoc);
+        assert_eq!(route_left, route_right);
+
+        // ...and the shared composite is a non-identity map, so the equality is
+        // not vacuous: (1,1,2) ↦ (f1,g1,h2) = (2,0,1), paired row-major.
+        assert_eq!(cat.dom(&route_left), 2 * 2 * 3);
+        assert_eq!(cat.cod(&route_left), 3 * 2 * 2);
+        // element (1,1,2) = ((1*2+1)*3+2) = 11 ↦ (2,0,1) = (2*2+0)*2+1 = 9.
+        assert_eq!(route_left.apply(11), 9);
+    }
+}
@@ -0,0 +1,251 @@
+//! Natural transformations between [`Functor`]s, with vertical and horizontal
+//! composition.
+//!
+//! Where a [`Functor`] maps one category into another, a *natural
+//! transformation* `η : F ⇒ G` maps one functor into another: it is a family of
+//! morphisms `η_A : F(A) → G(A)`, one for each object `A`, satisfying the
+//! **naturality** condition
+//!
+//! - `G(f) ∘ η_A = η_B ∘ F(f)`  for every `f : A → B`.
+//!
+//! Intuitively, it does not matter whether we first re-shape with `η` and then
+//! map with `G`, or first map with `F` and then re-shape with `η`.
+//!
+//! As with [`Functor`], the functors here are endofunctors on the function
+//! category, so the component `η_A` is simply a function `F<A> → G<A>`. The
+//! [`NaturalTransformation::component`] method realises that family, and the
+//! naturality square is checkable pointwise with [`check_naturality`]. Two
+//! families compose [`Vertical`]ly (componentwise `θ_A ∘ η_A`) and a family may
+//! be whiskered with a functor on either side ([`LeftWhisker`],
+//! [`RightWhisker`]).
+
+use crate::functor::{Compose, Functor};
+
+/// A natural transformation `η : F ⇒ G` between two endofunctors that share
+/// source and target categories.
+///
+/// The only datum is the family of components: [`component`](Self::component)
+/// produces `η_A : F<A> → G<A>` at the object `A` chosen by the caller. Whether
+/// it is *natural* — i.e. commutes with the functors' morphism maps — is a law
+/// to be checked, see [`check_naturality`].
+pub trait NaturalTransformation {
+    /// The source functor `F`.
+    type Source: Functor;
+
+    /// The target functor `G`.
+    type Target: Functor;
+
+    /// The component `η_A : F<A> → G<A>` at the object `A`.
+    fn component<A>(
+        &self,
+        fa: <Self::Source as Functor>::Map<A>,
+    ) -> <Self::Target as Functor>::Map<A>;
+}
+
+/// Checks the naturality square `G(f) ∘ η_A = η_B ∘ F(f)` at a single point
+/// `fa : F<A>` and morphism `f : A → B`.
+pub fn check_naturality<N, A, B, Fun>(eta: &N, f: Fun, fa: <N::Source as Functor>::Map<A>) -> bool
+where
+    N: NaturalTransformation,
+    Fun: Fn(A) -> B + Clone,
+    <N::Source as Functor>::Map<A>: Clone,
+    <N::Target as Functor>::Map<B>: PartialEq,
+{
+    // Go down then across: η_A first, then G(f).
+    let down_across = <N::Target as Functor>::map_mor(f.clone(), eta.component::<A>(fa.clone()));
+    // Go across then down: F(f) first, then η_B.
+    let across_down = eta.component::<B>(<N::Source as Functor>::map_mor(f, fa));
+    down_across == across_down
+}
+
+/// The vertical composite `θ • η : F ⇒ H` of `η : F ⇒ G` and `θ : G ⇒ H`.
+///
+/// Its component is the componentwise composite `θ_A ∘ η_A`, which is exactly
+/// what [`compose`](crate::compose) does to the two component functions.
+pub struct Vertical<Theta, Eta> {
+    theta: Theta,
+    eta: Eta,
+}
+
+impl<Theta, Eta> Vertical<Theta, Eta> {
+    /// Stacks `θ` on top of `η`, requiring `η`'s target functor to be `θ`'s
+    /// source functor.
+    pub fn new(theta: Theta, eta: Eta) -> Self {
+        Vertical { theta, eta }
+    }
+}
+
+impl<Theta, Eta> NaturalTransformation for Vertical<Theta, Eta>
+where
+    Eta: NaturalTransformation,
+    Theta: NaturalTransformation<Source = Eta::Target>,
+{
+    type Source = Eta::Source;
+    type Target = Theta::Target;
+
+    fn component<A>(
+        &self,
+        fa: <Self::Source as Functor>::Map<A>,
+    ) -> <Self::Target as Functor>::Map<A> {
+        self.theta.component::<A>(self.eta.component::<A>(fa))
+    }
+}
+
+/// The left whiskering `K ∘ η : K∘F ⇒ K∘G` of a family `η : F ⇒ G` by a functor
+/// `K` acting on the outside.
+///
+/// Its component at `A` applies `K` to the morphism `η_A`, i.e.
+/// `K(η_A) : K<F<A>> → K<G<A>>`.
+pub struct LeftWhisker<K, Eta> {
+    eta: Eta,
+    _functor: core::marker::PhantomData<K>,
+}
+
+impl<K, Eta> LeftWhisker<K, Eta> {
+    /// Whiskers `η` by the outer functor `K`.
+    pub fn new(eta: Eta) -> Self {
+        LeftWhisker {
+            eta,
+            _functor: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<K, Eta> NaturalTransformation for LeftWhisker<K, Eta>
+where
+    K: Functor,
+    Eta: NaturalTransformation,
+{
+    type Source = Compose<K, Eta::Source>;
+    type Target = Compose<K, Eta::Target>;
+
+    fn component<A>(
+        &self,
+        fa: <Self::Source as Functor>::Map<A>,
+    ) -> <Self::Target as Functor>::Map<A> {
+        K::map_mor(|inner| self.eta.component::<A>(inner), fa)
+    }
+}
+
+/// The right whiskering `η ∘ K : F∘K ⇒ G∘K` of a family `η : F ⇒ G` by a
+/// functor `K` acting on the inside.
+///
+/// Its component at `A` is simply `η_{K<A>}`, the original family read off at
+/// the object `K<A>`.
+pub struct RightWhisker<Eta, K> {
+    eta: Eta,
+    _functor: core::marker::PhantomData<K>,
+}
+
+impl<Eta, K> RightWhisker<Eta, K> {
+    /// Whiskers `η` by the inner functor `K`.
+    pub fn new(eta: Eta) -> Self {
+        RightWhisker {
+            eta,
+            _functor: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<Eta, K> NaturalTransformation for RightWhisker<Eta, K>
+where
+    Eta: NaturalTransformation,
+    K: Functor,
+{
+    type Source = Compose<Eta::Source, K>;
+    type Target = Compose<Eta::Target, K>;
+
+    fn component<A>(
+        &self,
+        fa: <Self::Source as Functor>::Map<A>,
+    ) -> <Self::Target as Functor>::Map<A> {
+        self.eta.component::<K::Map<A>>(fa)
+    }
+}
+
+/// The natural transformation `Vec ⇒ Option` taking a sequence to its first
+/// element, `[x, …] ↦ Some(x)` and `[] ↦ None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VecToOption;
+
+impl NaturalTransformation for VecToOption {
+    type Source = crate::functor::VecFunctor;
+    type Target = crate::functor::OptionFunctor;
+
+    fn component<A>(&self, fa: Vec<A>) -> Option<A> {
+        fa.into_iter().next()
+    }
+}
+
+/// The natural transformation `Option ⇒ Vec` taking `Some(x) ↦ [x]` and
+/// `None ↦ []`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OptionToVec;
+
+impl NaturalTransformation for OptionToVec {
+    type Source = crate::functor::OptionFunctor;
+    type Target = crate::functor::VecFunctor;
+
+    fn component<A>(&self, fa: Option<A>) -> Vec<A> {
+        fa.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_to_option_is_natural() {
+        let f = |x: i32| x * 2;
+        for xs in [vec![1, 2, 3], vec![7], vec![]] {
+            assert!(check_naturality(&VecToOption, f, xs));
+        }
+    }
+
+    #[test]
+    fn option_to_vec_is_natural() {
+        let f = |x: i32| x + 1;
+        for o in [Some(5), None] {
+            assert!(check_naturality(&OptionToVec, f, o));
+        }
+    }
+
+    #[test]
+    fn vertical_composition_is_the_round_trip() {
+        // Option ⇒ Vec ⇒ Option collapses to first-or-itself on the option.
+        let round_trip = Vertical::new(VecToOption, OptionToVec);
+        for o in [Some(9), None] {
+            assert_eq!(round_trip.component::<i32>(o), o);
+        }
+        let f = |x: i32| x - 4;
+        for o in [Some(2), None] {
+            assert!(check_naturality(&round_trip, f, o));
+        }
+    }
+
+    #[test]
+    fn left_whiskering_stays_natural() {
+        // Option ∘ (Vec ⇒ Option) : Option<Vec<A>> ⇒ Option<Option<A>>.
+        let whiskered = LeftWhisker::<crate::functor::OptionFunctor, _>::new(VecToOption);
+        let f = |x: i32| x * 3;
+        for o in [Some(vec![1, 2]), Some(vec![]), None] {
+            assert!(check_naturality(&whiskered, f, o));
+        }
+        assert_eq!(whiskered.component::<i32>(Some(vec![8, 9])), Some(Some(8)));
+    }
+
+    #[test]
+    fn right_whiskering_stays_natural() {
+        // (Vec ⇒ Option) ∘ Option : Vec<Option<A>> ⇒ Option<Option<A>>.
+        let whiskered = RightWhisker::<_, crate::functor::OptionFunctor>::new(VecToOption);
+        let f = |x: i32| x + 10;
+        for v in [vec![Some(1), None], vec![], vec![None]] {
+            assert!(check_naturality(&whiskered, f, v));
+        }
+        assert_eq!(
+            whiskered.component::<i32>(vec![Some(4), Some(5)]),
+            Some(Some(4))
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! An object-free (arrows-only) encoding of a finite category.
+//!
+//! The [`category`](crate::category) module describes a category as objects
+//! *and* morphisms. There is a dual, equally standard formulation in which only
+//! the **arrows** are primitive and a **partial** composition ties them
+//! together: `g ∘ f` is defined exactly when `f`'s codomain meets `g`'s domain.
+//! Objects are then *recovered* rather than given — they are the identity
+//! arrows `e`, the ones that act as units (`e ∘ f = f` and `g ∘ e = g` whenever
+//! the composite is defined).
+//!
+//! This lets us model small concrete categories — a finite graph together with
+//! a chosen composition — that the compile-time [`compose`](crate::compose)
+//! cannot express, since it can only chain morphisms whose Rust types already
+//! line up. Here composability is a run-time fact recorded in a table, and
+//! [`ArrowsOnly::new`] validates the category axioms before handing back a
+//! value, reporting the first law that fails via [`AxiomViolation`].
+
+/// An arrow of an [`ArrowsOnly`] category, identified by its index `0..arrows`.
+pub type Arrow = usize;
+
+/// A finite category presented purely by its arrows and a partial composition.
+///
+/// Arrows are the indices `0..arrows`. The composition is stored as a table,
+/// `table[g][f] = Some(g ∘ f)` when the pair is composable and `None`
+/// otherwise; read it through [`compose_partial`](Self::compose_partial).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArrowsOnly {
+    arrows: usize,
+    table: Vec<Vec<Option<Arrow>>>,
+}
+
+/// The axiom that a candidate composition table failed, returned by
+/// [`ArrowsOnly::check`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AxiomViolation {
+    /// An entry of the table named an arrow outside `0..arrows`.
+    ArrowOutOfRange { composite: Arrow },
+    /// An arrow has no identity acting as its domain (no `e` with `f ∘ e = f`).
+    MissingDomain { arrow: Arrow },
+    /// An arrow has no identity acting as its codomain (no `e` with `e ∘ f = f`).
+    MissingCodomain { arrow: Arrow },
+    /// An arrow had more than one identity serving as a domain or codomain.
+    NonUniqueIdentity { arrow: Arrow },
+    /// Composability disagreed with the endpoints: `g ∘ f` is defined iff
+    /// `cod(f) == dom(g)`, and its endpoints must be `dom(f)` and `cod(g)`.
+    CompositionMismatch { g: Arrow, f: Arrow },
+    /// Associativity failed: `h ∘ (g ∘ f) ≠ (h ∘ g) ∘ f` where both are defined.
+    Associativity { h: Arrow, g: Arrow, f: Arrow },
+}
+
+impl ArrowsOnly {
+    /// Builds a category from `arrows` arrows and a composition table, returning
+    /// the first [`AxiomViolation`] if the table does not present a category.
+    ///
+    /// `table[g][f]` is the composite `g ∘ f` (or `None` if not composable); the
+    /// table must be `arrows × arrows`.
+    pub fn new(arrows: usize, table: Vec<Vec<Option<Arrow>>>) -> Result<Self, AxiomViolation> {
+        assert_eq!(table.len(), arrows, "table must have one row per arrow");
+        assert!(
+            table.iter().all(|row| row.len() == arrows),
+            "table must have one column per arrow"
+        );
+        let cat = ArrowsOnly { arrows, table };
+        cat.check()?;
+        Ok(cat)
+    }
+
+    /// The number of arrows in the category.
+    pub fn arrows(&self) -> usize {
+        self.arrows
+    }
+
+    /// The partial composite `g ∘ f`, `Some` exactly when `f`'s codomain matches
+    /// `g`'s domain.
+    pub fn compose_partial(&self, g: Arrow, f: Arrow) -> Option<Arrow> {
+        self.table[g][f]
+    }
+
+    /// Whether `e` is an identity arrow: a unit for composition on both sides,
+    /// `e ∘ f = f` and `g ∘ e = g` wherever those composites are defined.
+    pub fn is_identity(&self, e: Arrow) -> bool {
+        (0..self.arrows).all(|f| self.table[e][f].is_none_or(|c| c == f))
+            && (0..self.arrows).all(|g| self.table[g][e].is_none_or(|c| c == g))
+    }
+
+    /// The identity arrows, i.e. the recovered objects of the category.
+    pub fn identities(&self) -> Vec<Arrow> {
+        (0..self.arrows).filter(|&e| self.is_identity(e)).collect()
+    }
+
+    /// The domain of `f`: the unique identity `e` with `f ∘ e = f`.
+    ///
+    /// Panics if the category has not been validated (see [`new`](Self::new)),
+    /// so that `dom` is well defined.
+    pub fn dom(&self, f: Arrow) -> Arrow {
+        self.find_dom(f).expect("validated category has a domain")
+    }
+
+    /// The codomain of `f`: the unique identity `e` with `e ∘ f = f`.
+    ///
+    /// Panics if the category has not been validated (see [`new`](Self::new)),
+    /// so that `cod` is well defined.
+    pub fn cod(&self, f: Arrow) -> Arrow {
+        self.find_cod(f).expect("validated category has a codomain")
+    }
+
+    fn find_dom(&self, f: Arrow) -> Option<Arrow> {
+        self.identities()
+            .into_iter()
+            .find(|&e| self.table[f][e] == Some(f))
+    }
+
+    fn find_cod(&self, f: Arrow) -> Option<Arrow> {
+        self.identities()
+            .into_iter()
+            .find(|&e| self.table[e][f] == Some(f))
+    }
+
+    /// The run-time checker: verifies the category axioms, returning `Ok(())`
+    /// or the first [`AxiomViolation`] encountered.
+    pub fn check(&self) -> Result<(), AxiomViolation> {
+        // Every named composite must be an actual arrow.
+        for g in 0..self.arrows {
+            for f in 0..self.arrows {
+                if let Some(c) = self.table[g][f] {
+                    if c >= self.arrows {
+                        return Err(AxiomViolation::ArrowOutOfRange { composite: c });
+                    }
+                }
+            }
+        }
+
+        let identities = self.identities();
+
+        // Every arrow has exactly one domain identity and one codomain identity.
+        for f in 0..self.arrows {
+            let doms: Vec<_> = identities
+                .iter()
+                .filter(|&&e| self.table[f][e] == Some(f))
+                .collect();
+            let cods: Vec<_> = identities
+                .iter()
+                .filter(|&&e| self.table[e][f] == Some(f))
+                .collect();
+            if doms.is_empty() {
+                return Err(AxiomViolation::MissingDomain { arrow: f });
+            }
+            if cods.is_empty() {
+                return Err(AxiomViolation::MissingCodomain { arrow: f });
+            }
+            if doms.len() > 1 || cods.len() > 1 {
+                return Err(AxiomViolation::NonUniqueIdentity { arrow: f });
+            }
+        }
+
+        // Composability matches endpoints: g ∘ f defined ⇔ cod(f) == dom(g),
+        // and the composite runs from dom(f) to cod(g).
+        for g in 0..self.arrows {
+            for f in 0..self.arrows {
+                let composable = self.cod(f) == self.dom(g);
+                match self.table[g][f] {
+                    Some(c) => {
+                        if !composable
+                            || self.dom(c) != self.dom(f)
+                            || self.cod(c) != self.cod(g)
+                        {
+                            return Err(AxiomViolation::CompositionMismatch { g, f });
+                        }
+                    }
+                    None => {
+                        if composable {
+                            return Err(AxiomViolation::CompositionMismatch { g, f });
+                        }
+                    }
+                }
+            }
+        }
+
+        // Associativity wherever both composites are defined.
+        for h in 0..self.arrows {
+            for g in 0..self.arrows {
+                for f in 0..self.arrows {
+                    if let (Some(gf), Some(hg)) = (self.table[g][f], self.table[h][g]) {
+                        if self.table[h][gf] != self.table[hg][f] {
+                            return Err(AxiomViolation::Associativity { h, g, f });
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The arrow category `0 --f--> 1`: identities `e0`, `e1` and a single
+    /// non-identity arrow `f : e0 → e1`.
+    ///
+    /// Arrows are indexed `0 = e0`, `1 = e1`, `2 = f`.
+    fn arrow_category() -> ArrowsOnly {
+        let n = None;
+        // table[g][f] = g ∘ f
+        let table = vec![
+            // g = e0
+            vec![Some(0), n, n],
+            // g = e1
+            vec![n, Some(1), Some(2)],
+            // g = f
+            vec![Some(2), n, n],
+        ];
+        ArrowsOnly::new(3, table).expect("arrow category is a category")
+    }
+
+    #[test]
+    fn identities_are_the_objects() {
+        let cat = arrow_category();
+        assert_eq!(cat.identities(), vec![0, 1]);
+    }
+
+    #[test]
+    fn dom_and_cod_recover_endpoints() {
+        let cat = arrow_category();
+        assert_eq!(cat.dom(2), 0);
+        assert_eq!(cat.cod(2), 1);
+        assert_eq!(cat.dom(0), 0);
+        assert_eq!(cat.cod(0), 0);
+    }
+
+    #[test]
+    fn composition_is_partial() {
+        let cat = arrow_category();
+        assert_eq!(cat.compose_partial(1, 2), Some(2)); // e1 ∘ f = f
+        assert_eq!(cat.compose_partial(2, 0), Some(2)); // f ∘ e0 = f
+        assert_eq!(cat.compose_partial(2, 1), None); // f ∘ e1 undefined
+    }
+
+    #[test]
+    fn missing_identity_is_reported() {
+        // A lone arrow whose only composite 0 ∘ 0 is left undefined. It passes
+        // `is_identity` vacuously, but no `e` satisfies `0 ∘ e = 0`, so it has
+        // no domain (defining 0 ∘ 0 = 0 would make it its own domain).
+        let table = vec![vec![None]];
+        assert_eq!(
+            ArrowsOnly::new(1, table),
+            Err(AxiomViolation::MissingDomain { arrow: 0 })
+        );
+    }
+
+    #[test]
+    fn broken_associativity_is_reported() {
+        // A one-object category (identity e = 0) carrying a non-associative
+        // binary operation on the endo-arrows a = 1, b = 2. Endpoints stay
+        // consistent — every arrow is an endo of e — so only associativity can
+        // fail, and it does: (a ∘ a) ∘ b ≠ a ∘ (a ∘ b).
+        let table = vec![
+            vec![Some(0), Some(1), Some(2)], // e ∘ x = x
+            vec![Some(1), Some(2), Some(2)], // a ∘ e=a, a ∘ a=b, a ∘ b=b
+            vec![Some(2), Some(1), Some(1)], // b ∘ e=b, b ∘ a=a, b ∘ b=a
+        ];
+        assert!(matches!(
+            ArrowsOnly::new(3, table),
+            Err(AxiomViolation::Associativity { .. })
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+//! Variadic composition and a left-to-right pipeline macro.
+//!
+//! The binary [`compose`](crate::compose) combines exactly two functions, so a
+//! longer chain has to be spelled out as nested calls like
+//! `compose(compose(f, g), h)`. The macros here fold that nesting away:
+//!
+//! - [`compose_all!`](crate::compose_all) takes any number of functions and
+//!   returns their composite, applied in the order written (`f` then `g` then
+//!   `h`), matching how [`compose`] chains its two arguments.
+//! - [`pipe!`](crate::pipe) threads a value through the same chain in reading
+//!   order, so `pipe!(x => f => g => h)` is `h(g(f(x)))`.
+//!
+//! Because associativity is already established for the binary [`compose`], the
+//! variadic form is just a fold and every parenthesisation agrees.
+
+/// Composes any number of functions left to right, applying the first argument
+/// first.
+///
+/// `compose_all!(f, g, h)` is `f` then `g` then `h`, equivalent to the nested
+/// `compose(f, compose(g, h))`.
+#[macro_export]
+macro_rules! compose_all {
+    ($f:expr $(,)?) => { $f };
+    ($f:expr, $($rest:expr),+ $(,)?) => {
+        $crate::compose($f, $crate::compose_all!($($rest),+))
+    };
+}
+
+/// Threads a value through a chain of functions in reading order.
+///
+/// `pipe!(x => f => g => h)` applies `f` to `x`, then `g`, then `h`, i.e.
+/// `h(g(f(x)))`.
+#[macro_export]
+macro_rules! pipe {
+    ($x:expr => $($f:expr)=>+) => {
+        $crate::compose_all!($($f),+)($x)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compose;
+
+    #[test]
+    fn compose_all_matches_binary_compose() {
+        let f = |x: i32| x + 1;
+        let g = |x: i32| x * 2;
+        let h = |x: i32| x - 3;
+
+        let variadic = compose_all!(f, g, h);
+        let left_assoc = compose(compose(f, g), h);
+        let right_assoc = compose(f, compose(g, h));
+
+        for x in -10..10 {
+            assert_eq!(variadic(x), left_assoc(x));
+            assert_eq!(variadic(x), right_assoc(x));
+            // f then g then h, spelled out.
+            assert_eq!(variadic(x), h(g(f(x))));
+        }
+    }
+
+    #[test]
+    fn compose_all_single_and_trailing_comma() {
+        let f = |x: i32| x * 5;
+        let single = compose_all!(f);
+        let trailing = compose_all!(f, |x: i32| x + 1,);
+        for x in -10..10 {
+            assert_eq!(single(x), f(x));
+            assert_eq!(trailing(x), f(x) + 1);
+        }
+    }
+
+    #[test]
+    fn pipe_reads_left_to_right() {
+        let add_one = |x: i32| x + 1;
+        let double = |x: i32| x * 2;
+        let negate = |x: i32| -x;
+
+        for x in -10..10 {
+            assert_eq!(pipe!(x => add_one => double => negate), negate(double(add_one(x))));
+        }
+        assert_eq!(pipe!(5 => add_one => double), 12);
+    }
+}